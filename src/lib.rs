@@ -1,16 +1,53 @@
 use std::{
+    cmp::Ordering,
+    marker::PhantomData,
+    mem,
     ops::{Deref, DerefMut, Index, IndexMut, Range},
-    slice,
+    ptr, slice,
     slice::SliceIndex,
     sync::Arc, fmt::Display,
 };
 
 use serde::{Serialize, Deserialize};
 
+pub trait Backing<T>: Clone {
+    type Owned;
+
+    fn as_slice(&self) -> &[T];
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn try_unwrap(self) -> Result<Self::Owned, Self>;
+}
+
+impl<T> Backing<T> for Arc<Vec<T>> {
+    type Owned = Vec<T>;
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        &self[..]
+    }
+
+    #[inline]
+    fn try_unwrap(self) -> Result<Vec<T>, Self> {
+        Arc::try_unwrap(self)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
-pub struct Snap<T> {
-    buf: Arc<Vec<T>>,
+pub struct Snap<T, B: Backing<T> = Arc<Vec<T>>> {
+    buf: B,
     range: Range<usize>,
+    #[serde(skip)]
+    _marker: PhantomData<T>,
 }
 
 impl<T> Snap<T> {
@@ -19,9 +56,15 @@ impl<T> Snap<T> {
         let range = 0..vec.len();
         let buf = Arc::new(vec);
 
-        Snap { buf, range }
+        Snap {
+            buf,
+            range,
+            _marker: PhantomData,
+        }
     }
+}
 
+impl<T, B: Backing<T>> Snap<T, B> {
     #[inline]
     pub fn snap(self, at: usize) -> (Self, Self) {
         assert!((0..=self.len()).contains(&at), "`snap`-ing out of range");
@@ -36,10 +79,12 @@ impl<T> Snap<T> {
             Snap {
                 buf: left_buf,
                 range: left_range,
+                _marker: PhantomData,
             },
             Snap {
                 buf: right_buf,
                 range: right_range,
+                _marker: PhantomData,
             },
         )
     }
@@ -47,7 +92,7 @@ impl<T> Snap<T> {
     #[inline]
     pub fn merge(left: Self, right: Self) -> Self {
         assert!(
-            left.buf.as_ptr() == right.buf.as_ptr(),
+            left.buf.as_slice().as_ptr() == right.buf.as_slice().as_ptr(),
             "merging `Snaps` of different origins"
         );
 
@@ -59,7 +104,11 @@ impl<T> Snap<T> {
         let buf = left.buf;
         let range = left.range.start..right.range.end;
 
-        Snap { buf, range }
+        Snap {
+            buf,
+            range,
+            _marker: PhantomData,
+        }
     }
 
     #[inline]
@@ -92,12 +141,12 @@ impl<T> Snap<T> {
 
     #[inline]
     pub fn as_slice(&self) -> &[T] {
-        &self.buf[self.range.clone()]
+        &self.buf.as_slice()[self.range.clone()]
     }
 
     #[inline]
     pub fn as_mut_slice(&mut self) -> &mut [T] {
-        let ptr = self.buf[self.range.clone()].as_ptr() as *mut T;
+        let ptr = self.buf.as_slice()[self.range.clone()].as_ptr() as *mut T;
         let len = self.len();
 
         unsafe { slice::from_raw_parts_mut(ptr, len) }
@@ -121,18 +170,327 @@ impl<T> Snap<T> {
     }
 
     #[inline]
-    pub fn try_unwrap(self) -> Result<Vec<T>, Self> {
-        match Arc::try_unwrap(self.buf) {
-            Ok(vec) => Ok(vec),
-            Err(arc) => Err(Snap {
-                buf: arc,
+    pub fn try_unwrap(self) -> Result<B::Owned, Self> {
+        match self.buf.try_unwrap() {
+            Ok(owned) => Ok(owned),
+            Err(buf) => Err(Snap {
+                buf,
                 range: self.range,
+                _marker: PhantomData,
             }),
         }
     }
+
+    #[inline]
+    pub fn split_first(self) -> Option<(Self, Self)> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.snap(1))
+        }
+    }
+
+    #[inline]
+    pub fn split_last(self) -> Option<(Self, Self)> {
+        if self.is_empty() {
+            None
+        } else {
+            let at = self.len() - 1;
+            Some(self.snap(at))
+        }
+    }
+
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<&T> {
+        if self.range.is_empty() {
+            return None;
+        }
+
+        let idx = self.range.start;
+        self.range.start += 1;
+
+        Some(&self.buf.as_slice()[idx])
+    }
+
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<&mut T> {
+        if self.range.is_empty() {
+            return None;
+        }
+
+        self.range.end -= 1;
+        let idx = self.range.end;
+        let ptr = self.buf.as_slice().as_ptr() as *mut T;
+
+        // `idx` just left `self.range`, so no other `Snap` can alias it.
+        Some(unsafe { &mut *ptr.add(idx) })
+    }
+
+    #[inline]
+    pub fn snap_by<F>(self, mut pred: F) -> (Self, Self)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let at = self.as_slice().partition_point(|item| pred(item));
+        self.snap(at)
+    }
+}
+
+impl<T: Ord, B: Backing<T>> Snap<T, B> {
+    #[inline]
+    pub fn snap_at(&self, value: &T) -> (Self, Self) {
+        let at = self.as_slice().partition_point(|item| item < value);
+        self.clone().snap(at)
+    }
 }
 
-impl<T, I: SliceIndex<[T]>> Index<I> for Snap<T> {
+const PAR_SORT_THRESHOLD: usize = 20;
+
+impl<T: Ord + Send + Sync> Snap<T> {
+    #[inline]
+    pub fn par_sort(self) -> Self {
+        self.par_sort_by(Ord::cmp)
+    }
+
+    pub fn par_sort_by<F>(mut self, compare: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        if self.len() <= PAR_SORT_THRESHOLD {
+            insertion_sort(self.as_mut_slice(), &compare);
+            return self;
+        }
+
+        reverse_descending_runs(self.as_mut_slice(), &compare);
+
+        let workers = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .max(1);
+        let chunk_size = self.len().div_ceil(workers);
+
+        let sorted_chunks = std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .chunks(chunk_size)
+                .into_iter()
+                .map(|mut chunk| {
+                    let compare = &compare;
+                    scope.spawn(move || {
+                        insertion_or_merge_sort(chunk.as_mut_slice(), compare);
+                        chunk
+                    })
+                })
+                .collect();
+
+            handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+        });
+
+        sorted_chunks
+            .into_iter()
+            .reduce(|left, right| merge_sorted(left, right, &compare))
+            .expect("`chunks` always yields at least one piece")
+    }
+}
+
+fn insertion_sort<T, F: Fn(&T, &T) -> Ordering>(slice: &mut [T], compare: &F) {
+    for i in 1..slice.len() {
+        let mut j = i;
+        while j > 0 && compare(&slice[j - 1], &slice[j]) == Ordering::Greater {
+            slice.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+fn reverse_descending_runs<T, F: Fn(&T, &T) -> Ordering>(slice: &mut [T], compare: &F) {
+    let mut start = 0;
+    while start < slice.len() {
+        let mut end = start + 1;
+        if end < slice.len() && compare(&slice[end], &slice[start]) == Ordering::Less {
+            // Strictly-decreasing only: equal elements must not be reversed, or stability breaks.
+            while end < slice.len() && compare(&slice[end], &slice[end - 1]) == Ordering::Less {
+                end += 1;
+            }
+            slice[start..end].reverse();
+        } else {
+            while end < slice.len() && compare(&slice[end], &slice[end - 1]) != Ordering::Less {
+                end += 1;
+            }
+        }
+        start = end;
+    }
+}
+
+fn insertion_or_merge_sort<T, F: Fn(&T, &T) -> Ordering>(slice: &mut [T], compare: &F) {
+    if slice.len() <= PAR_SORT_THRESHOLD {
+        insertion_sort(slice, compare);
+        return;
+    }
+
+    let mid = slice.len() / 2;
+    insertion_or_merge_sort(&mut slice[..mid], compare);
+    insertion_or_merge_sort(&mut slice[mid..], compare);
+
+    // `merged` holds bitwise copies of elements still owned by `slice`, so it's
+    // backed by `MaybeUninit` rather than `T`: if `compare` panics partway
+    // through, dropping `merged` must not also drop those elements.
+    let mut merged: Vec<mem::MaybeUninit<T>> = Vec::with_capacity(slice.len());
+    let (left, right) = slice.split_at_mut(mid);
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if compare(&right[j], &left[i]) == Ordering::Less {
+            merged.push(mem::MaybeUninit::new(unsafe { ptr::read(&right[j]) }));
+            j += 1;
+        } else {
+            merged.push(mem::MaybeUninit::new(unsafe { ptr::read(&left[i]) }));
+            i += 1;
+        }
+    }
+    while i < left.len() {
+        merged.push(mem::MaybeUninit::new(unsafe { ptr::read(&left[i]) }));
+        i += 1;
+    }
+    while j < right.len() {
+        merged.push(mem::MaybeUninit::new(unsafe { ptr::read(&right[j]) }));
+        j += 1;
+    }
+    unsafe {
+        ptr::copy_nonoverlapping(merged.as_ptr() as *const T, slice.as_mut_ptr(), slice.len());
+    }
+}
+
+fn merge_sorted<T, F: Fn(&T, &T) -> Ordering>(left: Snap<T>, right: Snap<T>, compare: &F) -> Snap<T> {
+    let left_len = left.len();
+    let mut combined = Snap::merge(left, right);
+    let combined_len = combined.len();
+
+    // Same reasoning as `insertion_or_merge_sort`'s `merged`: these are
+    // duplicate bits of elements still owned by `out`, so they're kept as
+    // `MaybeUninit` to stay safe if `compare` panics mid-merge.
+    let mut scratch: Vec<mem::MaybeUninit<T>> = Vec::with_capacity(left_len);
+    let out = combined.as_mut_slice();
+
+    unsafe {
+        ptr::copy_nonoverlapping(
+            out.as_ptr() as *const mem::MaybeUninit<T>,
+            scratch.as_mut_ptr(),
+            left_len,
+        );
+        scratch.set_len(left_len);
+    }
+
+    let (mut i, mut j, mut k) = (0, left_len, 0);
+    while i < left_len && j < combined_len {
+        unsafe {
+            let order = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                compare(&out[j], scratch[i].assume_init_ref())
+            }));
+            let order = match order {
+                Ok(order) => order,
+                Err(payload) => {
+                    // Move the unmerged rest of the left run into the gap `out[k..j]`,
+                    // so `out` owns every element exactly once while unwinding.
+                    let rest = scratch.as_ptr().add(i) as *const T;
+                    ptr::copy_nonoverlapping(rest, out.as_mut_ptr().add(k), left_len - i);
+                    std::panic::resume_unwind(payload);
+                }
+            };
+            if order == Ordering::Less {
+                ptr::copy(&out[j], &mut out[k], 1);
+                j += 1;
+            } else {
+                ptr::copy(scratch[i].as_ptr(), &mut out[k], 1);
+                i += 1;
+            }
+        }
+        k += 1;
+    }
+    while i < left_len {
+        unsafe {
+            ptr::copy(scratch[i].as_ptr(), &mut out[k], 1);
+        }
+        i += 1;
+        k += 1;
+    }
+    while j < combined_len {
+        if k != j {
+            unsafe {
+                ptr::copy(&out[j], &mut out[k], 1);
+            }
+        }
+        j += 1;
+        k += 1;
+    }
+
+    combined
+}
+
+impl<T: Send + Sync> Snap<T> {
+    pub fn par_for_each<F>(&mut self, chunk_size: usize, f: F)
+    where
+        F: Fn(&mut [T]) + Sync,
+    {
+        let owned = mem::replace(self, Snap::new(Vec::new()));
+
+        let merged = std::thread::scope(|scope| {
+            let handles: Vec<_> = owned
+                .chunks(chunk_size)
+                .into_iter()
+                .map(|mut chunk| {
+                    let f = &f;
+                    scope.spawn(move || {
+                        f(chunk.as_mut_slice());
+                        chunk
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .reduce(Snap::merge)
+                .expect("`chunks` always yields at least one piece")
+        });
+
+        *self = merged;
+    }
+
+    pub fn par_map<F, R>(self, chunk_size: usize, f: F) -> (Self, Vec<R>)
+    where
+        F: Fn(&mut [T]) -> R + Sync,
+        R: Send,
+    {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .chunks(chunk_size)
+                .into_iter()
+                .map(|mut chunk| {
+                    let f = &f;
+                    scope.spawn(move || {
+                        let result = f(chunk.as_mut_slice());
+                        (chunk, result)
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .fold(None, |acc: Option<(Self, Vec<R>)>, (chunk, result)| {
+                    Some(match acc {
+                        None => (chunk, vec![result]),
+                        Some((merged, mut results)) => {
+                            results.push(result);
+                            (Snap::merge(merged, chunk), results)
+                        }
+                    })
+                })
+                .expect("`chunks` always yields at least one piece")
+        })
+    }
+}
+
+impl<T, B: Backing<T>, I: SliceIndex<[T]>> Index<I> for Snap<T, B> {
     type Output = I::Output;
 
     #[inline]
@@ -141,14 +499,14 @@ impl<T, I: SliceIndex<[T]>> Index<I> for Snap<T> {
     }
 }
 
-impl<T, I: SliceIndex<[T]>> IndexMut<I> for Snap<T> {
+impl<T, B: Backing<T>, I: SliceIndex<[T]>> IndexMut<I> for Snap<T, B> {
     #[inline]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         IndexMut::index_mut(self.as_mut_slice(), index)
     }
 }
 
-impl<'a, T> IntoIterator for &'a Snap<T> {
+impl<'a, T, B: Backing<T>> IntoIterator for &'a Snap<T, B> {
     type Item = &'a T;
     type IntoIter = slice::Iter<'a, T>;
 
@@ -157,7 +515,7 @@ impl<'a, T> IntoIterator for &'a Snap<T> {
     }
 }
 
-impl<'a, T> IntoIterator for &'a mut Snap<T> {
+impl<'a, T, B: Backing<T>> IntoIterator for &'a mut Snap<T, B> {
     type Item = &'a mut T;
     type IntoIter = slice::IterMut<'a, T>;
 
@@ -166,7 +524,7 @@ impl<'a, T> IntoIterator for &'a mut Snap<T> {
     }
 }
 
-impl<T> Deref for Snap<T> {
+impl<T, B: Backing<T>> Deref for Snap<T, B> {
     type Target = [T];
 
     fn deref(&self) -> &[T] {
@@ -174,22 +532,23 @@ impl<T> Deref for Snap<T> {
     }
 }
 
-impl<T> DerefMut for Snap<T> {
+impl<T, B: Backing<T>> DerefMut for Snap<T, B> {
     fn deref_mut(&mut self) -> &mut [T] {
         self.as_mut_slice()
     }
 }
 
-impl<T> Clone for Snap<T> {
+impl<T, B: Backing<T>> Clone for Snap<T, B> {
     fn clone(&self) -> Self {
         Snap {
             buf: self.buf.clone(),
             range: self.range.clone(),
+            _marker: PhantomData,
         }
     }
 }
 
-impl<T> Display for Snap<T>
+impl<T, B: Backing<T>> Display for Snap<T, B>
 where
     T: Display,
 {
@@ -408,4 +767,317 @@ mod tests {
         assert_eq!(*chunks[2].range(), 10..15);
         assert_eq!(*chunks[3].range(), 15..16);
     }
+
+    #[test]
+    fn split_first() {
+        let snap = Snap::new(vec![0, 1, 2, 3]);
+        let (head, rest) = snap.split_first().unwrap();
+
+        assert_eq!(head[..], [0]);
+        assert_eq!(rest[..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn split_first_empty() {
+        let snap: Snap<i32> = Snap::new(Vec::new());
+        assert!(snap.split_first().is_none());
+    }
+
+    #[test]
+    fn split_last() {
+        let snap = Snap::new(vec![0, 1, 2, 3]);
+        let (rest, tail) = snap.split_last().unwrap();
+
+        assert_eq!(rest[..], [0, 1, 2]);
+        assert_eq!(tail[..], [3]);
+    }
+
+    #[test]
+    fn split_last_empty() {
+        let snap: Snap<i32> = Snap::new(Vec::new());
+        assert!(snap.split_last().is_none());
+    }
+
+    #[test]
+    fn pop_front() {
+        let mut snap = Snap::new(vec![0, 1, 2, 3]);
+
+        assert_eq!(snap.pop_front(), Some(&0));
+        assert_eq!(snap.pop_front(), Some(&1));
+        assert_eq!(snap[..], [2, 3]);
+    }
+
+    #[test]
+    fn pop_front_empty() {
+        let mut snap: Snap<i32> = Snap::new(Vec::new());
+        assert_eq!(snap.pop_front(), None);
+    }
+
+    #[test]
+    fn pop_back() {
+        let mut snap = Snap::new(vec![0, 1, 2, 3]);
+
+        assert_eq!(snap.pop_back(), Some(&mut 3));
+        assert_eq!(snap.pop_back(), Some(&mut 2));
+        assert_eq!(snap[..], [0, 1]);
+    }
+
+    #[test]
+    fn pop_back_empty() {
+        let mut snap: Snap<i32> = Snap::new(Vec::new());
+        assert_eq!(snap.pop_back(), None);
+    }
+
+    #[test]
+    fn pop_front_then_merge() {
+        let snap = Snap::new(vec![0, 1, 2, 3]);
+        let (mut popped, rest) = snap.snap(1);
+        popped.pop_front();
+
+        let merged = Snap::merge(popped, rest);
+        assert_eq!(merged[..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn snap_by() {
+        let snap = Snap::new(vec![0, 1, 2, 3, 4, 5]);
+        let (left, right) = snap.snap_by(|&item| item < 3);
+
+        assert_eq!(left[..], [0, 1, 2]);
+        assert_eq!(right[..], [3, 4, 5]);
+    }
+
+    #[test]
+    fn snap_by_all_false() {
+        let snap = Snap::new(vec![0, 1, 2, 3]);
+        let (left, right) = snap.snap_by(|_| false);
+
+        assert_eq!(left[..], []);
+        assert_eq!(right[..], [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn snap_at() {
+        let snap = Snap::new(vec![0, 1, 2, 4, 5]);
+        let (left, right) = snap.snap_at(&3);
+
+        assert_eq!(left[..], [0, 1, 2]);
+        assert_eq!(right[..], [4, 5]);
+    }
+
+    #[test]
+    fn snap_at_past_end() {
+        let snap = Snap::new(vec![0, 1, 2, 3]);
+        let (left, right) = snap.snap_at(&10);
+
+        assert_eq!(left[..], [0, 1, 2, 3]);
+        assert_eq!(right[..], []);
+    }
+
+    impl<T> Backing<T> for std::rc::Rc<Vec<T>> {
+        type Owned = Vec<T>;
+
+        fn as_slice(&self) -> &[T] {
+            &self[..]
+        }
+
+        fn try_unwrap(self) -> Result<Vec<T>, Self> {
+            std::rc::Rc::try_unwrap(self)
+        }
+    }
+
+    #[test]
+    fn custom_backing() {
+        use std::rc::Rc;
+
+        let snap: Snap<i32, Rc<Vec<i32>>> = Snap {
+            buf: Rc::new(vec![0, 1, 2, 3]),
+            range: 0..4,
+            _marker: PhantomData,
+        };
+
+        let (left, right) = snap.snap(2);
+        assert_eq!(left[..], [0, 1]);
+        assert_eq!(right[..], [2, 3]);
+
+        let snap = Snap::merge(left, right);
+        assert_eq!(snap[..], [0, 1, 2, 3]);
+        assert_eq!(snap.try_unwrap().unwrap(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn par_sort_small() {
+        let snap = Snap::new(vec![5, 3, 1, 4, 2]);
+        let snap = snap.par_sort();
+        assert_eq!(snap[..], [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn par_sort_large_reversed() {
+        let mut vec: Vec<i32> = (0..1000).collect();
+        vec.reverse();
+        let snap = Snap::new(vec);
+        let snap = snap.par_sort();
+
+        let mut expected: Vec<i32> = (0..1000).collect();
+        expected.sort();
+        assert!(snap.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn par_sort_already_sorted() {
+        let vec: Vec<i32> = (0..200).collect();
+        let snap = Snap::new(vec.clone());
+        let snap = snap.par_sort();
+        assert!(snap.iter().eq(vec.iter()));
+    }
+
+    #[test]
+    fn par_sort_empty() {
+        let snap: Snap<i32> = Snap::new(Vec::new());
+        let snap = snap.par_sort();
+        assert_eq!(snap.len(), 0);
+    }
+
+    #[test]
+    fn par_sort_by_descending() {
+        let snap = Snap::new(vec![1, 5, 3, 2, 4]);
+        let snap = snap.par_sort_by(|a, b| b.cmp(a));
+        assert_eq!(snap[..], [5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn par_sort_by_stable_with_ties() {
+        let mut vec = vec![(2, 0), (1, 1), (1, 2), (1, 3), (1, 4)];
+        vec.extend((3..28).map(|key| (key, key)));
+
+        let snap = Snap::new(vec);
+        let snap = snap.par_sort_by(|a, b| a.0.cmp(&b.0));
+
+        let tags: Vec<i32> = snap
+            .iter()
+            .filter(|&&(key, _)| key == 1)
+            .map(|&(_, tag)| tag)
+            .collect();
+        assert_eq!(tags, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn par_sort_by_inconsistent_comparator_does_not_corrupt_buffer() {
+        let vec: Vec<i32> = (0..100).collect();
+        let snap = Snap::new(vec.clone());
+        let snap = snap.par_sort_by(|_, _| Ordering::Equal);
+
+        let mut sorted = snap.as_slice().to_vec();
+        sorted.sort();
+        assert_eq!(sorted, vec);
+    }
+
+    #[test]
+    fn par_sort_by_panicking_comparator_does_not_double_drop() {
+        use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+        struct CountedDrop<'a> {
+            drops: &'a AtomicUsize,
+            key: i32,
+        }
+        impl Drop for CountedDrop<'_> {
+            fn drop(&mut self) {
+                self.drops.fetch_add(1, AtomicOrdering::SeqCst);
+            }
+        }
+        impl PartialEq for CountedDrop<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+        impl Eq for CountedDrop<'_> {}
+        impl PartialOrd for CountedDrop<'_> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for CountedDrop<'_> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        fn make_vec(drops: &AtomicUsize, count: i32) -> Vec<CountedDrop<'_>> {
+            (0..count)
+                .map(|i| CountedDrop {
+                    drops,
+                    key: (i * 97) % count,
+                })
+                .collect()
+        }
+
+        let count = 200;
+
+        // First pass: count how many comparisons a full sort makes, with no
+        // panics, so the second pass can pick a call number that's guaranteed
+        // to land inside the merge (not merely the initial run-detection).
+        let total_calls = AtomicUsize::new(0);
+        let drops = AtomicUsize::new(0);
+        let snap = Snap::new(make_vec(&drops, count));
+        let snap = snap.par_sort_by(|a, b| {
+            total_calls.fetch_add(1, AtomicOrdering::SeqCst);
+            a.key.cmp(&b.key)
+        });
+        drop(snap);
+        let total_calls = total_calls.load(AtomicOrdering::SeqCst);
+
+        let drops = AtomicUsize::new(0);
+        let calls = AtomicUsize::new(0);
+        let snap = Snap::new(make_vec(&drops, count));
+        let panic_at = total_calls / 2;
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            snap.par_sort_by(|a, b| {
+                if calls.fetch_add(1, AtomicOrdering::SeqCst) == panic_at {
+                    panic!("comparator failure");
+                }
+                a.key.cmp(&b.key)
+            })
+        }));
+
+        assert!(result.is_err());
+        drop(result);
+        assert_eq!(drops.load(AtomicOrdering::SeqCst), count as usize);
+    }
+
+    #[test]
+    fn par_for_each() {
+        let mut snap = Snap::new(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        snap.par_for_each(3, |chunk| {
+            for item in chunk {
+                *item += 1;
+            }
+        });
+
+        assert_eq!(snap[..], [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert!(snap.is_complete());
+    }
+
+    #[test]
+    fn par_for_each_empty() {
+        let mut snap: Snap<i32> = Snap::new(Vec::new());
+        snap.par_for_each(4, |chunk| assert!(chunk.is_empty()));
+        assert_eq!(snap.len(), 0);
+    }
+
+    #[test]
+    fn par_map() {
+        let snap = Snap::new(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let (snap, sums) = snap.par_map(3, |chunk| {
+            for item in chunk.iter_mut() {
+                *item *= 2;
+            }
+            chunk.iter().sum::<i32>()
+        });
+
+        assert_eq!(snap[..], [0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+        assert_eq!(sums, vec![6, 24, 42, 18]);
+        assert!(snap.is_complete());
+    }
 }